@@ -1,32 +1,56 @@
 mod types;
 use crate::types::{
-    ErrorResponse, HealthResponse, InvokeRequest, InvokeResponse, Task, TaskInfo, TaskListResponse,
+    ErrorResponse, HealthResponse, InvokeRequest, InvokeResponse, Task, TaskCondition, TaskInfo,
+    TaskListResponse, TaskStatus,
 };
 use axum::{
     Json, Router,
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Request, State},
+    http::{StatusCode, header},
+    middleware::{self, Next},
+    response::sse::{Event as SseEvent, KeepAlive, Sse},
     response::{IntoResponse, Response},
     routing::{get, post},
 };
+use axum_server::tls_rustls::RustlsConfig;
 use futures::StreamExt;
 use k8s_openapi::api::batch::v1::{Job, JobSpec};
 use k8s_openapi::api::core::v1::{
-    Container, EnvVar, PodSpec, PodTemplateSpec, ResourceRequirements,
+    Container, EnvVar, Pod, PodSpec, PodTemplateSpec, ResourceRequirements,
 };
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use kube::{
     Client, ResourceExt,
-    api::{Api, ObjectMeta, PostParams},
+    api::{Api, ListParams, LogParams, ObjectMeta, Patch, PatchParams, PostParams},
     runtime::controller::{Action, Controller},
+    runtime::finalizer::{Event as FinalizerEvent, finalizer},
+    runtime::wait::await_condition,
+    runtime::watcher,
 };
-use std::collections::BTreeMap;
+use lru::LruCache;
+use std::collections::{BTreeMap, HashMap};
 use std::net::SocketAddr;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use thiserror::Error;
+use tokio::sync::{Mutex, Semaphore, mpsc, oneshot};
 use tower_http::trace::TraceLayer;
 use tracing::{error, info, warn};
+use utoipa::{
+    Modify, OpenApi,
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+};
+use utoipa_swagger_ui::SwaggerUi;
+
+/// Size of the in-memory idempotency cache keyed by
+/// `<namespace>/<task>/<request-id>`.
+const IDEMPOTENCY_CACHE_SIZE: usize = 1024;
+
+/// Finalizer applied to every `Task` so in-flight Jobs can be observed
+/// before the owning Task is removed from the API server.
+const TASK_FINALIZER: &str = "lambda.example.com/task-protection";
 
 #[derive(Error, Debug)]
 pub enum OperatorError {
@@ -44,6 +68,15 @@ pub enum OperatorError {
 
     #[error("Invalid request: {0}")]
     InvalidRequest(String),
+
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
 }
 
 impl IntoResponse for OperatorError {
@@ -54,6 +87,9 @@ impl IntoResponse for OperatorError {
             Self::ConfigError(ref msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
             Self::KubeError(ref e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
             Self::SerdeError(ref e) => (StatusCode::BAD_REQUEST, e.to_string()),
+            Self::Timeout(ref msg) => (StatusCode::GATEWAY_TIMEOUT, msg.clone()),
+            Self::Unauthorized => (StatusCode::UNAUTHORIZED, self.to_string()),
+            Self::ServiceUnavailable(ref msg) => (StatusCode::SERVICE_UNAVAILABLE, msg.clone()),
         };
 
         let body = Json(ErrorResponse {
@@ -69,16 +105,48 @@ impl IntoResponse for OperatorError {
 struct OperatorConfig {
     http_port: u16,
     default_namespace: String,
+    tls_enabled: bool,
+    tls_cert_path: Option<String>,
+    tls_key_path: Option<String>,
+    api_auth_token: Option<String>,
+    max_concurrent_jobs: usize,
+    queue_capacity: usize,
 }
 
 impl OperatorConfig {
     fn from_env() -> Result<Self, OperatorError> {
+        let tls_cert_path = std::env::var("TLS_CERT_PATH").ok();
+        let tls_key_path = std::env::var("TLS_KEY_PATH").ok();
+        let tls_enabled = std::env::var("TLS_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        if tls_enabled && (tls_cert_path.is_none() || tls_key_path.is_none()) {
+            return Err(OperatorError::ConfigError(
+                "TLS_ENABLED is set but TLS_CERT_PATH/TLS_KEY_PATH are missing".to_string(),
+            ));
+        }
+
         Ok(Self {
             http_port: std::env::var("HTTP_PORT")
                 .unwrap_or_else(|_| "8080".to_string())
                 .parse()
                 .map_err(|_| OperatorError::ConfigError("Invalid HTTP_PORT".to_string()))?,
             default_namespace: std::env::var("NAMESPACE").unwrap_or_else(|_| "default".to_string()),
+            tls_enabled,
+            tls_cert_path,
+            tls_key_path,
+            api_auth_token: std::env::var("API_AUTH_TOKEN").ok(),
+            max_concurrent_jobs: std::env::var("MAX_CONCURRENT_JOBS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .map_err(|_| {
+                    OperatorError::ConfigError("Invalid MAX_CONCURRENT_JOBS".to_string())
+                })?,
+            queue_capacity: std::env::var("QUEUE_CAPACITY")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .map_err(|_| OperatorError::ConfigError("Invalid QUEUE_CAPACITY".to_string()))?,
         })
     }
 }
@@ -87,31 +155,236 @@ impl OperatorConfig {
 struct AppState {
     client: Client,
     config: OperatorConfig,
+    /// In-memory dedup cache so retried invocations with the same
+    /// `request_id` return the original result instead of launching
+    /// another Job. Backed by a cluster-level lookup so the guarantee
+    /// survives operator restarts.
+    idempotency_cache: Arc<Mutex<LruCache<String, InvokeResponse>>>,
+    /// Admission queue for Job creation, drained by `run_job_scheduler`
+    /// while respecting `MAX_CONCURRENT_JOBS` / `TaskSpec::max_concurrency`.
+    scheduler_tx: mpsc::Sender<PendingInvocation>,
+    queue_depth: Arc<AtomicUsize>,
+}
+
+/// A Job creation waiting for an open concurrency slot.
+struct PendingInvocation {
+    namespace: String,
+    task: Task,
+    request: InvokeRequest,
+    request_id: String,
+    job_name: String,
+    response_tx: oneshot::Sender<Result<(), OperatorError>>,
 }
 
-async fn reconcile_task(task: Arc<Task>, _ctx: Arc<AppState>) -> Result<Action, OperatorError> {
+async fn reconcile_task(task: Arc<Task>, ctx: Arc<AppState>) -> Result<Action, OperatorError> {
+    let namespace = task
+        .namespace()
+        .unwrap_or_else(|| ctx.config.default_namespace.clone());
+    let tasks: Api<Task> = Api::namespaced(ctx.client.clone(), &namespace);
+
+    finalizer(&tasks, TASK_FINALIZER, task, |event| async {
+        match event {
+            FinalizerEvent::Apply(task) => apply_task(&ctx.client, &task, &namespace).await,
+            FinalizerEvent::Cleanup(task) => cleanup_task(&ctx.client, &task, &namespace).await,
+        }
+    })
+    .await
+    .map_err(|e| OperatorError::ConfigError(e.to_string()))
+}
+
+/// Most conditions we keep in `TaskStatus`, so a long-lived Task doesn't
+/// grow an unbounded status object across thousands of invocations. Jobs
+/// still present in the cluster always keep their condition, so the list
+/// can exceed this when more Jobs than this are live.
+const MAX_TRACKED_CONDITIONS: usize = 50;
+
+/// Roll the Jobs created for `task` up into its `TaskStatus`. Counters are
+/// cumulative: Jobs are GC'd `ttlSecondsAfterFinished` after they finish, so
+/// `executions`/`succeeded`/`failed` are carried forward from the previous
+/// status and only incremented for newly observed Jobs/phase transitions,
+/// rather than recomputed from the (shrinking) live Job list.
+async fn apply_task(client: &Client, task: &Task, namespace: &str) -> Result<Action, OperatorError> {
     info!("Reconciling task: {}", task.name_any());
+
+    let jobs: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let lp = ListParams::default().labels(&format!("task={}", task.name_any()));
+    let job_list = jobs.list(&lp).await?;
+
+    let previous = task.status.clone().unwrap_or_default();
+    let previous_by_job: BTreeMap<String, &TaskCondition> = previous
+        .conditions
+        .iter()
+        .map(|c| (c.job_name.clone(), c))
+        .collect();
+
+    let mut active = 0i64;
+    let mut new_executions = 0i64;
+    let mut new_succeeded = 0i64;
+    let mut new_failed = 0i64;
+    let mut conditions = Vec::with_capacity(job_list.items.len());
+
+    for job in &job_list.items {
+        let job_name = job.name_any();
+        let request_id = job
+            .labels()
+            .get("request-id")
+            .cloned()
+            .unwrap_or_default();
+        let job_status = job.status.clone().unwrap_or_default();
+        let previous_condition = previous_by_job.get(&job_name).copied();
+
+        let phase = if job_status.succeeded.unwrap_or(0) > 0 {
+            "Succeeded"
+        } else if job_status.failed.unwrap_or(0) > 0 {
+            "Failed"
+        } else {
+            active += 1;
+            "Active"
+        };
+
+        let previous_phase = previous_condition.map(|c| c.phase.as_str());
+        if previous_phase.is_none() {
+            new_executions += 1;
+        }
+        if phase == "Succeeded" && previous_phase != Some("Succeeded") {
+            new_succeeded += 1;
+        }
+        if phase == "Failed" && previous_phase != Some("Failed") {
+            new_failed += 1;
+        }
+
+        // Only bump the transition timestamp when the phase actually
+        // changed, so an unchanged Job doesn't make the status look
+        // different every reconcile and trigger a needless patch.
+        let last_transition = if previous_phase == Some(phase) {
+            previous_condition
+                .map(|c| c.last_transition.clone())
+                .unwrap_or_else(|| chrono::Utc::now().to_rfc3339())
+        } else {
+            chrono::Utc::now().to_rfc3339()
+        };
+
+        conditions.push(TaskCondition {
+            request_id,
+            job_name,
+            phase: phase.to_string(),
+            last_transition,
+        });
+    }
+
+    // Jobs already GC'd by `ttlSecondsAfterFinished` keep their last-known
+    // condition so completed-job history isn't lost once the Job disappears.
+    // Only this appended history is capped: every Job still in `job_list` is
+    // still physically present in the cluster, so dropping one of those
+    // conditions would make `previous_by_job` forget it next reconcile and
+    // double-count it into `executions`/`succeeded`/`failed` all over again.
+    let live_count = conditions.len();
+    for previous_condition in &previous.conditions {
+        if !conditions
+            .iter()
+            .any(|c| c.job_name == previous_condition.job_name)
+        {
+            conditions.push(previous_condition.clone());
+        }
+    }
+    conditions.truncate(live_count.max(MAX_TRACKED_CONDITIONS));
+
+    let status = TaskStatus {
+        executions: previous.executions + new_executions,
+        succeeded: previous.succeeded + new_succeeded,
+        failed: previous.failed + new_failed,
+        last_execution: Some(chrono::Utc::now().to_rfc3339()),
+        conditions,
+    };
+
+    let unchanged = status.executions == previous.executions
+        && status.succeeded == previous.succeeded
+        && status.failed == previous.failed
+        && status.conditions == previous.conditions;
+
+    if unchanged {
+        info!(
+            "Task {} status unchanged ({} active), skipping patch",
+            task.name_any(),
+            active
+        );
+    } else {
+        let tasks: Api<Task> = Api::namespaced(client.clone(), namespace);
+        tasks
+            .patch_status(
+                &task.name_any(),
+                &PatchParams::apply("lambda-job-operator"),
+                &Patch::Merge(serde_json::json!({ "status": status })),
+            )
+            .await?;
+
+        info!(
+            "Reconciled task {}: {} active, {} succeeded, {} failed",
+            task.name_any(),
+            active,
+            status.succeeded,
+            status.failed
+        );
+    }
+
     Ok(Action::requeue(Duration::from_secs(300)))
 }
 
+/// Defer finalizer removal until no Jobs for this Task are still active, so
+/// in-flight executions can actually be observed before the Task goes away.
+async fn cleanup_task(
+    client: &Client,
+    task: &Task,
+    namespace: &str,
+) -> Result<Action, OperatorError> {
+    let active = count_active_jobs(client, namespace, &task.name_any()).await?;
+
+    if active > 0 {
+        info!(
+            "Task {} has {} active job(s), deferring finalizer removal",
+            task.name_any(),
+            active
+        );
+        return Err(OperatorError::ConfigError(format!(
+            "{active} job(s) for task {} still active",
+            task.name_any()
+        )));
+    }
+
+    info!(
+        "Task {} has no active jobs remaining, finalizer cleanup complete",
+        task.name_any()
+    );
+    Ok(Action::await_change())
+}
+
 fn error_policy(_task: Arc<Task>, error: &OperatorError, _ctx: Arc<AppState>) -> Action {
     error!("Reconciliation error: {:?}", error);
     Action::requeue(Duration::from_secs(60))
 }
 
+/// Compute the Job name up front so callers can hand it back to clients
+/// (e.g. as part of a "queued" response) before the Job actually exists.
+/// Mixes in `request_id` alongside the second-granularity timestamp since
+/// admitting a burst of invocations for the same task within one wall-clock
+/// second is expected, and the timestamp alone can't tell those apart.
+fn generate_job_name(task: &Task, request_id: &str) -> String {
+    format!(
+        "{}-{}-{}",
+        task.name_any(),
+        chrono::Utc::now().timestamp(),
+        request_id
+    )
+}
+
 async fn create_job_for_task(
     client: &Client,
     task: &Task,
     request: &InvokeRequest,
+    request_id: &str,
+    job_name: &str,
     namespace: &str,
-) -> Result<String, OperatorError> {
-    let request_id = request
-        .request_id
-        .clone()
-        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-
-    let job_name = format!("{}-{}", task.name_any(), chrono::Utc::now().timestamp());
-
+) -> Result<(), OperatorError> {
     info!("Creating job: {} for task: {}", job_name, task.name_any());
 
     // Build environment variables
@@ -128,7 +401,7 @@ async fn create_job_for_task(
         },
         EnvVar {
             name: "LAMBDA_REQUEST_ID".to_string(),
-            value: Some(request_id.clone()),
+            value: Some(request_id.to_string()),
             ..Default::default()
         },
         EnvVar {
@@ -196,13 +469,14 @@ async fn create_job_for_task(
     let mut labels = BTreeMap::new();
     labels.insert("app".to_string(), "lambda-task".to_string());
     labels.insert("task".to_string(), task.name_any());
-    labels.insert("request-id".to_string(), request_id.clone());
+    labels.insert("request-id".to_string(), request_id.to_string());
 
     let job = Job {
         metadata: ObjectMeta {
-            name: Some(job_name.clone()),
+            name: Some(job_name.to_string()),
             namespace: Some(namespace.to_string()),
             labels: Some(labels.clone()),
+            owner_references: task.controller_owner_ref(&()).map(|owner| vec![owner]),
             ..Default::default()
         },
         spec: Some(JobSpec {
@@ -229,11 +503,149 @@ async fn create_job_for_task(
     jobs.create(&PostParams::default(), &job).await?;
 
     info!("Job created successfully: {}", job_name);
-    Ok(job_name)
+    Ok(())
+}
+
+/// Wait until fewer than `limit` Jobs for `task_name` are still active,
+/// polling the cluster so admission respects the real-time Job count
+/// rather than just the in-process queue depth.
+async fn wait_for_capacity(
+    client: &Client,
+    namespace: &str,
+    task_name: &str,
+    limit: usize,
+) -> Result<(), OperatorError> {
+    loop {
+        if count_active_jobs(client, namespace, task_name).await? < limit {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+async fn count_active_jobs(
+    client: &Client,
+    namespace: &str,
+    task_name: &str,
+) -> Result<usize, OperatorError> {
+    let jobs: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let lp = ListParams::default().labels(&format!("task={task_name}"));
+    let list = jobs.list(&lp).await?;
+
+    Ok(list
+        .items
+        .iter()
+        .filter(|job| {
+            job.status
+                .as_ref()
+                .map(|status| status.succeeded.unwrap_or(0) == 0 && status.failed.unwrap_or(0) == 0)
+                .unwrap_or(true)
+        })
+        .count())
+}
+
+/// Per-task admission lock, keyed by Task name. Serializes the
+/// wait-for-capacity-then-create sequence for a given Task so two
+/// concurrently dispatched invocations can't both observe the same
+/// pre-creation Job count and both proceed to create a Job, blowing through
+/// `MAX_CONCURRENT_JOBS`/`maxConcurrency`.
+type AdmissionLocks = Arc<Mutex<HashMap<String, Arc<Semaphore>>>>;
+
+/// Drain the admission queue, dispatching each pending invocation to its own
+/// task. Each dispatched task independently waits for its own Task's
+/// concurrency slot, so one congested or stuck Task (e.g. Jobs that never
+/// terminate) can't head-of-line block admission for every other Task
+/// sharing the queue.
+async fn run_job_scheduler(
+    client: Client,
+    config: OperatorConfig,
+    queue_depth: Arc<AtomicUsize>,
+    mut rx: mpsc::Receiver<PendingInvocation>,
+) {
+    let admission_locks: AdmissionLocks = Arc::new(Mutex::new(HashMap::new()));
+
+    while let Some(pending) = rx.recv().await {
+        queue_depth.fetch_sub(1, Ordering::SeqCst);
+        tokio::spawn(dispatch_pending_invocation(
+            client.clone(),
+            config.clone(),
+            admission_locks.clone(),
+            pending,
+        ));
+    }
+}
+
+/// Wait for `pending`'s Task to have a free concurrency slot, then create its
+/// Job. The whole wait-then-create sequence runs under that Task's admission
+/// lock, held until the Job is actually created, so dispatched invocations
+/// for the same Task can't race each other's capacity check; the sequence as
+/// a whole is bounded by the Task's own timeout, so a permanently stuck Task
+/// fails its own waiters instead of starving them forever.
+async fn dispatch_pending_invocation(
+    client: Client,
+    config: OperatorConfig,
+    admission_locks: AdmissionLocks,
+    pending: PendingInvocation,
+) {
+    let limit = pending
+        .task
+        .spec
+        .max_concurrency
+        .filter(|&n| n > 0)
+        .map(|n| n as usize)
+        .unwrap_or(config.max_concurrent_jobs);
+    let task_name = pending.task.name_any();
+    let wait_timeout = Duration::from_secs(pending.task.spec.timeout.max(0) as u64);
+
+    let lock = admission_locks
+        .lock()
+        .await
+        .entry(task_name.clone())
+        .or_insert_with(|| Arc::new(Semaphore::new(1)))
+        .clone();
+
+    let outcome = tokio::time::timeout(wait_timeout, async {
+        let _permit = lock
+            .acquire_owned()
+            .await
+            .expect("admission semaphore is never closed");
+        wait_for_capacity(&client, &pending.namespace, &task_name, limit).await?;
+        create_job_for_task(
+            &client,
+            &pending.task,
+            &pending.request,
+            &pending.request_id,
+            &pending.job_name,
+            &pending.namespace,
+        )
+        .await
+    })
+    .await;
+
+    let result = match outcome {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err(OperatorError::Timeout(format!(
+            "task {task_name} stayed at its concurrency limit of {limit} for {}s",
+            wait_timeout.as_secs()
+        ))),
+    };
+
+    if let Err(ref e) = result {
+        error!("Scheduler failed to create job {}: {}", pending.job_name, e);
+    }
+
+    let _ = pending.response_tx.send(result);
 }
 
 // HTTP Handlers
 
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "tasks",
+    responses((status = 200, description = "Operator is healthy", body = HealthResponse))
+)]
 async fn health_check() -> Json<HealthResponse> {
     Json(HealthResponse {
         status: "healthy".to_string(),
@@ -241,6 +653,12 @@ async fn health_check() -> Json<HealthResponse> {
     })
 }
 
+#[utoipa::path(
+    get,
+    path = "/tasks",
+    tag = "tasks",
+    responses((status = 200, description = "List all Task custom resources", body = TaskListResponse))
+)]
 async fn list_tasks(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<TaskListResponse>, OperatorError> {
@@ -261,6 +679,16 @@ async fn list_tasks(
     Ok(Json(TaskListResponse { tasks: task_infos }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/tasks/{namespace}/{task_name}",
+    tag = "tasks",
+    params(
+        ("namespace" = String, Path, description = "Namespace the Task lives in"),
+        ("task_name" = String, Path, description = "Name of the Task custom resource"),
+    ),
+    responses((status = 200, description = "The Task custom resource", body = serde_json::Value))
+)]
 async fn get_task(
     State(state): State<Arc<AppState>>,
     Path((namespace, task_name)): Path<(String, String)>,
@@ -270,11 +698,27 @@ async fn get_task(
     Ok(Json(task))
 }
 
+#[utoipa::path(
+    post,
+    path = "/tasks/{namespace}/{task_name}/invoke",
+    tag = "tasks",
+    params(
+        ("namespace" = String, Path, description = "Namespace the Task lives in"),
+        ("task_name" = String, Path, description = "Name of the Task custom resource"),
+    ),
+    request_body = InvokeRequest,
+    responses(
+        (status = 200, description = "asyncMode:true (default): invocation accepted and queued", body = InvokeResponse, content_type = "application/json"),
+        (status = 200, description = "asyncMode:false: Server-Sent Events stream, one `log` event per line of captured stdout followed by a final `result` event whose JSON payload is an InvokeResponse; a non-zero exit or timeout is reported in that payload's `status` field, not the HTTP status", body = String, content_type = "text/event-stream"),
+        (status = 503, description = "Admission queue is full", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
 async fn invoke_task(
     State(state): State<Arc<AppState>>,
     Path((namespace, task_name)): Path<(String, String)>,
     Json(request): Json<InvokeRequest>,
-) -> Result<Json<InvokeResponse>, OperatorError> {
+) -> Result<Response, OperatorError> {
     info!("Invoking task: {} in namespace: {}", task_name, namespace);
 
     // Get the task CRD
@@ -284,38 +728,438 @@ async fn invoke_task(
         .await
         .map_err(|_| OperatorError::TaskNotFound(task_name.clone()))?;
 
-    // Create job
-    let job_name = create_job_for_task(&state.client, &task, &request, &namespace).await?;
-
+    let async_mode = request.async_mode.unwrap_or(true);
+    let timeout = Duration::from_secs(task.spec.timeout.max(0) as u64);
     let request_id = request
         .request_id
+        .clone()
         .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let cache_key = format!("{namespace}/{task_name}/{request_id}");
 
-    Ok(Json(InvokeResponse {
+    if let Some(cached) = state.idempotency_cache.lock().await.get(&cache_key).cloned() {
+        info!(
+            "Duplicate invocation for request_id {}, returning cached result",
+            request_id
+        );
+        return Ok(Json(InvokeResponse {
+            status: "duplicate".to_string(),
+            queue_position: None,
+            ..cached
+        })
+        .into_response());
+    }
+
+    if let Some(existing_job) =
+        find_job_by_request_id(&state.client, &namespace, &task_name, &request_id).await?
+    {
+        info!(
+            "Duplicate invocation for request_id {}, found existing job {}",
+            request_id, existing_job
+        );
+        let response = InvokeResponse {
+            request_id: request_id.clone(),
+            job_name: existing_job,
+            status: "duplicate".to_string(),
+            namespace: namespace.clone(),
+            task_name: task_name.clone(),
+            output: None,
+            exit_code: None,
+            queue_position: None,
+        };
+        state
+            .idempotency_cache
+            .lock()
+            .await
+            .put(cache_key, response.clone());
+        return Ok(Json(response).into_response());
+    }
+
+    // Admit the Job creation through the scheduler instead of calling
+    // create_job_for_task directly, so a burst of invocations can't
+    // overwhelm the namespace's Job quota.
+    let job_name = generate_job_name(&task, &request_id);
+    let (response_tx, response_rx) = oneshot::channel();
+    let position = state.queue_depth.fetch_add(1, Ordering::SeqCst) + 1;
+
+    if state
+        .scheduler_tx
+        .try_send(PendingInvocation {
+            namespace: namespace.clone(),
+            task,
+            request: request.clone(),
+            request_id: request_id.clone(),
+            job_name: job_name.clone(),
+            response_tx,
+        })
+        .is_err()
+    {
+        state.queue_depth.fetch_sub(1, Ordering::SeqCst);
+        return Err(OperatorError::ServiceUnavailable(
+            "invocation queue is full, try again later".to_string(),
+        ));
+    }
+
+    if !async_mode {
+        // A synchronous caller is already blocked on the response, so wait
+        // for the scheduler to actually create the Job before streaming logs.
+        response_rx
+            .await
+            .map_err(|_| OperatorError::ConfigError("scheduler dropped the request".to_string()))??;
+
+        return invoke_task_sync(
+            state.client.clone(),
+            namespace,
+            task_name,
+            job_name,
+            request_id,
+            timeout,
+            state.idempotency_cache.clone(),
+            cache_key,
+        )
+        .await;
+    }
+
+    let response = InvokeResponse {
         request_id,
         job_name,
-        status: "accepted".to_string(),
+        status: "queued".to_string(),
         namespace: namespace.clone(),
         task_name: task_name.clone(),
-    }))
+        output: None,
+        exit_code: None,
+        queue_position: Some(position),
+    };
+
+    state
+        .idempotency_cache
+        .lock()
+        .await
+        .put(cache_key.clone(), response.clone());
+
+    // The scheduler may still fail to create this Job (e.g. the admission
+    // wait times out). Without this, the cached "queued" response above
+    // would keep looking like a valid retry target forever, even though the
+    // Job it names was never created. Watch for that failure in the
+    // background and drop the cache entry so a retry with the same
+    // request_id falls through to `find_job_by_request_id` (which also
+    // won't find it) and admits a fresh Job instead of replaying a lie.
+    let idempotency_cache = state.idempotency_cache.clone();
+    tokio::spawn(async move {
+        if let Ok(Err(e)) = response_rx.await {
+            warn!(
+                "Scheduler failed to create job for cached request {}: {}, invalidating cached response",
+                cache_key, e
+            );
+            idempotency_cache.lock().await.pop(&cache_key);
+        }
+    });
+
+    Ok(Json(response).into_response())
+}
+
+/// Look up a Job already created for `task_name`/`request_id`, so a retried
+/// invocation short-circuits even after an operator restart clears the
+/// in-memory cache. Both labels must match: `request-id` alone isn't unique
+/// across Tasks sharing a namespace, and the in-memory cache key is
+/// `namespace/task_name/request_id`.
+async fn find_job_by_request_id(
+    client: &Client,
+    namespace: &str,
+    task_name: &str,
+    request_id: &str,
+) -> Result<Option<String>, OperatorError> {
+    let jobs: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let lp = ListParams::default().labels(&format!("task={task_name},request-id={request_id}"));
+    let list = jobs.list(&lp).await?;
+    Ok(list.items.into_iter().next().map(|job| job.name_any()))
 }
 
+#[utoipa::path(
+    post,
+    path = "/invoke/{task_name}",
+    tag = "tasks",
+    params(("task_name" = String, Path, description = "Name of the Task custom resource in the operator's default namespace")),
+    request_body = InvokeRequest,
+    responses(
+        (status = 200, description = "asyncMode:true (default): invocation accepted and queued", body = InvokeResponse, content_type = "application/json"),
+        (status = 200, description = "asyncMode:false: Server-Sent Events stream, one `log` event per line of captured stdout followed by a final `result` event whose JSON payload is an InvokeResponse; a non-zero exit or timeout is reported in that payload's `status` field, not the HTTP status", body = String, content_type = "text/event-stream"),
+        (status = 503, description = "Admission queue is full", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
 async fn invoke_task_default_namespace(
     State(state): State<Arc<AppState>>,
     Path(task_name): Path<String>,
     Json(request): Json<InvokeRequest>,
-) -> Result<Json<InvokeResponse>, OperatorError> {
+) -> Result<Response, OperatorError> {
     let namespace = state.config.default_namespace.clone();
     invoke_task(State(state), Path((namespace, task_name)), Json(request)).await
 }
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        health_check,
+        list_tasks,
+        get_task,
+        invoke_task,
+        invoke_task_default_namespace
+    ),
+    components(schemas(
+        InvokeRequest,
+        InvokeResponse,
+        ErrorResponse,
+        HealthResponse,
+        TaskListResponse,
+        TaskInfo
+    )),
+    tags((name = "tasks", description = "Lambda-like Task invocation API")),
+    modifiers(&SecurityAddon)
+)]
+struct ApiDoc;
+
+/// Declares the `bearer_auth` scheme `require_bearer_token` enforces on the
+/// invoke routes, so the generated contract matches what the server actually
+/// requires.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("token")
+                        .build(),
+                ),
+            );
+        }
+    }
+}
+
+/// Block until the Job's pod starts running (or has already finished), then
+/// stream its logs back to
+/// the caller as they are produced, the same way a CI driver ships job
+/// output to a client. The stream's final SSE event carries the
+/// `InvokeResponse` with the captured stdout and exit status, since the
+/// HTTP status code can no longer be changed once the body starts flowing.
+///
+/// Deliberate deviation: a non-zero exit or `active_deadline_seconds`
+/// timeout is reported as a `"failed"`/`"timeout"` `status` in that final
+/// `result` event, not as an HTTP 500/504 the way `OperatorError` maps
+/// errors elsewhere in this file. Streaming and status-code semantics are
+/// in tension here — by the time the Job's outcome is known, the response
+/// has already committed to 200 and started sending bytes, so there is no
+/// HTTP status left to change. Callers that only check the status code
+/// cannot see job failure this way; they must parse the `result` event.
+async fn invoke_task_sync(
+    client: Client,
+    namespace: String,
+    task_name: String,
+    job_name: String,
+    request_id: String,
+    timeout: Duration,
+    idempotency_cache: Arc<Mutex<LruCache<String, InvokeResponse>>>,
+    cache_key: String,
+) -> Result<Response, OperatorError> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod_name = tokio::time::timeout(timeout, wait_for_job_pod(&pods, &job_name))
+        .await
+        .map_err(|_| OperatorError::Timeout(format!("job {job_name} never scheduled a pod")))??;
+
+    // A short-lived pod can reach Succeeded/Failed before a Running phase is
+    // ever observed, so any of the three counts as started.
+    let pod_started = |pod: Option<&Pod>| {
+        matches!(
+            pod.and_then(|pod| pod.status.as_ref())
+                .and_then(|status| status.phase.as_deref()),
+            Some("Running" | "Succeeded" | "Failed")
+        )
+    };
+    tokio::time::timeout(timeout, await_condition(pods.clone(), &pod_name, pod_started))
+    .await
+    .map_err(|_| OperatorError::Timeout(format!("pod {pod_name} never started running")))?
+    .map_err(OperatorError::KubeError)?;
+
+    let (tx, rx) = mpsc::unbounded_channel::<SseEvent>();
+    tokio::spawn(stream_pod_outcome(
+        pods,
+        pod_name,
+        namespace,
+        task_name,
+        job_name,
+        request_id,
+        tx,
+        idempotency_cache,
+        cache_key,
+    ));
+
+    let stream = futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|event| (Ok::<_, std::convert::Infallible>(event), rx))
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()).into_response())
+}
+
+/// Find the pod created for `job_name` by polling the Job's `job-name`
+/// label selector until the Job controller has scheduled one.
+async fn wait_for_job_pod(pods: &Api<Pod>, job_name: &str) -> Result<String, OperatorError> {
+    let lp = ListParams::default().labels(&format!("job-name={job_name}"));
+    loop {
+        let list = pods.list(&lp).await?;
+        if let Some(pod) = list.items.into_iter().next() {
+            return Ok(pod.name_any());
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+/// Follow the pod's container logs, forwarding each line as an SSE `log`
+/// event, then emit a final `result` event with the full captured stdout
+/// and exit status once the container terminates.
+async fn stream_pod_outcome(
+    pods: Api<Pod>,
+    pod_name: String,
+    namespace: String,
+    task_name: String,
+    job_name: String,
+    request_id: String,
+    tx: mpsc::UnboundedSender<SseEvent>,
+    idempotency_cache: Arc<Mutex<LruCache<String, InvokeResponse>>>,
+    cache_key: String,
+) {
+    let log_params = LogParams {
+        follow: true,
+        container: Some("task".to_string()),
+        ..Default::default()
+    };
+
+    let mut output = String::new();
+    // Log chunks don't align with line boundaries; hold back a trailing
+    // partial line until the rest of it arrives.
+    let mut partial = String::new();
+
+    match pods.log_stream(&pod_name, &log_params).await {
+        Ok(mut lines) => {
+            while let Some(chunk) = lines.next().await {
+                match chunk {
+                    Ok(bytes) => {
+                        let text = String::from_utf8_lossy(&bytes).into_owned();
+                        output.push_str(&text);
+                        partial.push_str(&text);
+                        while let Some(end) = partial.find('\n') {
+                            let line: String = partial.drain(..=end).collect();
+                            let line = line.trim_end_matches(['\r', '\n']).to_string();
+                            let _ = tx.send(SseEvent::default().event("log").data(line));
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(SseEvent::default().event("error").data(e.to_string()));
+                        return;
+                    }
+                }
+            }
+            if !partial.is_empty() {
+                let line = partial.trim_end_matches('\r').to_string();
+                let _ = tx.send(SseEvent::default().event("log").data(line));
+            }
+        }
+        Err(e) => {
+            let _ = tx.send(SseEvent::default().event("error").data(e.to_string()));
+            return;
+        }
+    }
+
+    let pod_status = pods.get(&pod_name).await.ok().and_then(|pod| pod.status);
+    let deadline_exceeded = pod_status
+        .as_ref()
+        .and_then(|status| status.reason.as_deref())
+        == Some("DeadlineExceeded");
+    let exit_code = pod_status
+        .and_then(|status| status.container_statuses)
+        .and_then(|statuses| statuses.into_iter().find(|c| c.name == "task"))
+        .and_then(|c| c.state)
+        .and_then(|state| state.terminated)
+        .map(|terminated| terminated.exit_code);
+
+    let response = InvokeResponse {
+        request_id,
+        job_name,
+        status: match exit_code {
+            _ if deadline_exceeded => "timeout".to_string(),
+            Some(0) => "succeeded".to_string(),
+            Some(_) => "failed".to_string(),
+            None => "unknown".to_string(),
+        },
+        namespace,
+        task_name,
+        output: Some(output),
+        exit_code,
+        queue_position: None,
+    };
+
+    // Cache the real result so a retried request_id returns the original
+    // stdout/exit code instead of the empty `duplicate` fallback built from
+    // a bare cluster Job lookup.
+    idempotency_cache.lock().await.put(cache_key, response.clone());
+
+    if let Ok(json) = serde_json::to_string(&response) {
+        let _ = tx.send(SseEvent::default().event("result").data(json));
+    }
+}
+
+/// Gate the mutating invoke routes behind a shared-secret bearer token.
+/// A missing `API_AUTH_TOKEN` leaves the API open, matching the operator's
+/// previous behavior for clusters that front it with their own auth proxy.
+async fn require_bearer_token(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, OperatorError> {
+    let Some(expected) = state.config.api_auth_token.as_ref() else {
+        return Ok(next.run(req).await);
+    };
+
+    let provided = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match provided {
+        Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => {
+            Ok(next.run(req).await)
+        }
+        _ => Err(OperatorError::Unauthorized),
+    }
+}
+
+/// Compare two byte strings in time independent of where they first differ,
+/// so a timing side channel can't be used to guess the configured token.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 fn create_router(state: Arc<AppState>) -> Router {
+    let invoke_routes = Router::new()
+        .route("/tasks/:namespace/:task_name/invoke", post(invoke_task))
+        .route("/invoke/:task_name", post(invoke_task_default_namespace))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_bearer_token,
+        ));
+
     Router::new()
         .route("/health", get(health_check))
         .route("/tasks", get(list_tasks))
         .route("/tasks/:namespace/:task_name", get(get_task))
-        .route("/tasks/:namespace/:task_name/invoke", post(invoke_task))
-        .route("/invoke/:task_name", post(invoke_task_default_namespace))
+        .merge(invoke_routes)
+        .merge(SwaggerUi::new("/docs").url("/openapi.json", ApiDoc::openapi()))
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
@@ -338,30 +1182,47 @@ async fn main() -> anyhow::Result<()> {
     let client = Client::try_default().await?;
     info!("Kubernetes client initialized");
 
+    let (scheduler_tx, scheduler_rx) = mpsc::channel(config.queue_capacity);
+    let queue_depth = Arc::new(AtomicUsize::new(0));
+
     let state = Arc::new(AppState {
         client: client.clone(),
         config: config.clone(),
+        idempotency_cache: Arc::new(Mutex::new(LruCache::new(
+            NonZeroUsize::new(IDEMPOTENCY_CACHE_SIZE).expect("cache size is non-zero"),
+        ))),
+        scheduler_tx,
+        queue_depth: queue_depth.clone(),
     });
 
-    // Start controller for Task CRD
+    // Drain the admission queue in the background, gating Job creation on
+    // MAX_CONCURRENT_JOBS / TaskSpec::max_concurrency.
+    tokio::spawn(run_job_scheduler(
+        client.clone(),
+        config.clone(),
+        queue_depth,
+        scheduler_rx,
+    ));
+
+    // Start controller for Task CRD, watching the Jobs it owns so that
+    // Job status changes (success/failure) trigger a Task reconciliation.
     let tasks: Api<Task> = Api::all(client.clone());
-    let controller = Controller::new(tasks, Default::default())
+    let jobs: Api<Job> = Api::all(client.clone());
+    let controller = Controller::new(tasks, watcher::Config::default())
+        .owns(jobs, watcher::Config::default())
         .run(reconcile_task, error_policy, state.clone())
         .for_each(|_| futures::future::ready(()));
 
     // Create HTTP server
     let app = create_router(state);
     let addr = SocketAddr::from(([0, 0, 0, 0], config.http_port));
-    info!("Starting HTTP server on {}", addr);
-
-    let listener = tokio::net::TcpListener::bind(addr).await?;
 
     // Run both controller and HTTP server
     tokio::select! {
         _ = controller => {
             warn!("Controller stopped");
         }
-        result = axum::serve(listener, app) => {
+        result = run_http_server(app, addr, &config) => {
             if let Err(e) = result {
                 error!("HTTP server error: {}", e);
             }
@@ -371,3 +1232,27 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Serve `app` on `addr`, terminating TLS with `axum-server`/`rustls` when
+/// `TLS_ENABLED` is set (using `TLS_CERT_PATH`/`TLS_KEY_PATH`), falling back
+/// to the plain `axum::serve` path otherwise, even if the cert/key paths are set.
+async fn run_http_server(
+    app: Router,
+    addr: SocketAddr,
+    config: &OperatorConfig,
+) -> std::io::Result<()> {
+    if config.tls_enabled {
+        let cert_path = config.tls_cert_path.clone().expect("validated in from_env");
+        let key_path = config.tls_key_path.clone().expect("validated in from_env");
+        info!("Starting HTTPS server on {} (cert={})", addr, cert_path);
+
+        let tls_config = RustlsConfig::from_pem_file(cert_path, key_path).await?;
+        axum_server::bind_rustls(addr, tls_config)
+            .serve(app.into_make_service())
+            .await
+    } else {
+        info!("Starting HTTP server on {}", addr);
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        axum::serve(listener, app).await
+    }
+}
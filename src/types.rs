@@ -1,6 +1,7 @@
 use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 #[derive(CustomResource, Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[kube(
@@ -34,6 +35,10 @@ pub struct TaskSpec {
     /// Timeout in seconds
     #[serde(default = "default_timeout")]
     pub timeout: i64,
+
+    /// Per-task override of the operator-wide `MAX_CONCURRENT_JOBS` limit
+    #[serde(default)]
+    pub max_concurrency: Option<i64>,
 }
 
 fn default_pull_policy() -> String {
@@ -71,15 +76,36 @@ pub struct TaskEnvVar {
     pub value: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStatus {
+    #[serde(default)]
     pub executions: i64,
+
+    #[serde(default)]
+    pub succeeded: i64,
+
+    #[serde(default)]
+    pub failed: i64,
+
     pub last_execution: Option<String>,
+
+    /// Per-request-id outcome of the Jobs created for this Task
+    #[serde(default)]
+    pub conditions: Vec<TaskCondition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskCondition {
+    pub request_id: String,
+    pub job_name: String,
+    pub phase: String,
+    pub last_transition: String,
 }
 
 // HTTP API request/response types
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InvokeRequest {
     pub kwargs: serde_json::Value,
@@ -89,7 +115,7 @@ pub struct InvokeRequest {
     pub async_mode: Option<bool>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InvokeResponse {
     pub request_id: String,
@@ -97,28 +123,43 @@ pub struct InvokeResponse {
     pub status: String,
     pub namespace: String,
     pub task_name: String,
+
+    /// Captured stdout of the Job's pod, only populated for synchronous
+    /// (`asyncMode: false`) invocations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+
+    /// Exit status of the Job's pod, only populated for synchronous
+    /// (`asyncMode: false`) invocations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+
+    /// Position in the admission queue, only populated when `status` is
+    /// `"queued"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_position: Option<usize>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub error: String,
     pub details: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct HealthResponse {
     pub status: String,
     pub version: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskListResponse {
     pub tasks: Vec<TaskInfo>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskInfo {
     pub name: String,